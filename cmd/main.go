@@ -19,6 +19,8 @@ package main
 import (
 	"flag"
 	"os"
+	"strings"
+	"time"
 
 	// Import all Kubernetes client auth plugins (e.g. Azure, GCP, OIDC, etc.)
 	// to ensure that exec-entrypoint and run can make use of them.
@@ -28,6 +30,7 @@ import (
 	utilruntime "k8s.io/apimachinery/pkg/util/runtime"
 	clientgoscheme "k8s.io/client-go/kubernetes/scheme"
 	ctrl "sigs.k8s.io/controller-runtime"
+	"sigs.k8s.io/controller-runtime/pkg/cache"
 	"sigs.k8s.io/controller-runtime/pkg/healthz"
 	"sigs.k8s.io/controller-runtime/pkg/log/zap"
 	metricsserver "sigs.k8s.io/controller-runtime/pkg/metrics/server"
@@ -53,11 +56,23 @@ func main() {
 	var metricsAddr string
 	var enableLeaderElection bool
 	var probeAddr string
+	var dryRun bool
+	var requeueInterval time.Duration
+	var watchNamespaces string
 	flag.StringVar(&metricsAddr, "metrics-bind-address", ":8080", "The address the metric endpoint binds to.")
 	flag.StringVar(&probeAddr, "health-probe-bind-address", ":8081", "The address the probe endpoint binds to.")
 	flag.BoolVar(&enableLeaderElection, "leader-elect", false,
 		"Enable leader election for controller manager. "+
 			"Enabling this will ensure there is only one active controller manager.")
+	flag.BoolVar(&dryRun, "dry-run", false,
+		"Submit every write made by the reconcilers as a server-side dry run and log the resulting object. "+
+			"Events are logged instead of recorded. "+
+			"Objects are validated by the API server but never persisted; reads and leader election are unaffected.")
+	flag.DurationVar(&requeueInterval, "requeue-interval", controller.DefaultRequeueInterval,
+		"The interval after which a successfully reconciled XlineCluster is reconciled again. "+
+			"0 disables periodic requeue.")
+	flag.StringVar(&watchNamespaces, "watch-namespaces", "",
+		"Comma-separated list of namespaces the manager watches. Watches all namespaces if empty.")
 	opts := zap.Options{
 		Development: true,
 	}
@@ -66,8 +81,20 @@ func main() {
 
 	ctrl.SetLogger(zap.New(zap.UseFlagOptions(&opts)))
 
+	if requeueInterval < 0 {
+		setupLog.Error(nil, "invalid --requeue-interval, must not be negative", "requeue-interval", requeueInterval)
+		os.Exit(1)
+	}
+
+	namespaces := parseNamespaces(watchNamespaces)
+	if watchNamespaces != "" && len(namespaces) == 0 {
+		setupLog.Error(nil, "invalid --watch-namespaces, no namespace given", "watch-namespaces", watchNamespaces)
+		os.Exit(1)
+	}
+
 	mgr, err := ctrl.NewManager(ctrl.GetConfigOrDie(), ctrl.Options{
 		Scheme:                 scheme,
+		Cache:                  cache.Options{DefaultNamespaces: namespaces},
 		Metrics:                metricsserver.Options{BindAddress: metricsAddr},
 		HealthProbeBindAddress: probeAddr,
 		LeaderElection:         enableLeaderElection,
@@ -89,9 +116,19 @@ func main() {
 		os.Exit(1)
 	}
 
+	reconcilerClient := mgr.GetClient()
+	recorder := mgr.GetEventRecorderFor("xlinecluster-controller")
+	if dryRun {
+		setupLog.Info("dry-run mode enabled, reconciler writes and events will not be persisted")
+		reconcilerClient = controller.NewDryRunClient(reconcilerClient)
+		recorder = controller.NewDryRunRecorder(ctrl.Log.WithName("events"))
+	}
+
 	if err = (&controller.XlineClusterReconciler{
-		Client: mgr.GetClient(),
-		Scheme: mgr.GetScheme(),
+		Client:          reconcilerClient,
+		Scheme:          mgr.GetScheme(),
+		RequeueInterval: requeueInterval,
+		Recorder:        recorder,
 	}).SetupWithManager(mgr); err != nil {
 		setupLog.Error(err, "unable to create controller", "controller", "XlineCluster")
 		os.Exit(1)
@@ -113,3 +150,20 @@ func main() {
 		os.Exit(1)
 	}
 }
+
+// parseNamespaces turns a comma-separated namespace list into the cache's
+// per-namespace configuration. A nil map makes the cache watch all namespaces.
+func parseNamespaces(list string) map[string]cache.Config {
+	var namespaces map[string]cache.Config
+	for _, ns := range strings.Split(list, ",") {
+		ns = strings.TrimSpace(ns)
+		if ns == "" {
+			continue
+		}
+		if namespaces == nil {
+			namespaces = map[string]cache.Config{}
+		}
+		namespaces[ns] = cache.Config{}
+	}
+	return namespaces
+}
@@ -0,0 +1,54 @@
+/*
+Copyright 2023.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+package main
+
+import (
+	"reflect"
+	"testing"
+
+	"sigs.k8s.io/controller-runtime/pkg/cache"
+)
+
+func TestParseNamespaces(t *testing.T) {
+	tests := []struct {
+		name string
+		list string
+		want map[string]cache.Config
+	}{
+		{name: "empty watches all namespaces", list: "", want: nil},
+		{name: "only separators", list: ",", want: nil},
+		{name: "only whitespace", list: " ", want: nil},
+		{name: "single namespace", list: "xline", want: map[string]cache.Config{"xline": {}}},
+		{
+			name: "trims whitespace and skips empty entries",
+			list: " xline , ,default ",
+			want: map[string]cache.Config{"xline": {}, "default": {}},
+		},
+		{
+			name: "collapses duplicates",
+			list: "xline,xline, xline",
+			want: map[string]cache.Config{"xline": {}},
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := parseNamespaces(tt.list); !reflect.DeepEqual(got, tt.want) {
+				t.Errorf("parseNamespaces(%q) = %v, want %v", tt.list, got, tt.want)
+			}
+		})
+	}
+}
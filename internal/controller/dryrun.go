@@ -0,0 +1,151 @@
+/*
+Copyright 2023.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+package controller
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/go-logr/logr"
+	"k8s.io/apimachinery/pkg/runtime"
+	"k8s.io/client-go/tools/record"
+	"sigs.k8s.io/controller-runtime/pkg/client"
+	"sigs.k8s.io/controller-runtime/pkg/log"
+)
+
+// NewDryRunClient wraps c so that every write, including subresource writes,
+// is submitted as a server-side dry run. The object returned by the API server
+// is logged through the request logger instead of being persisted. Reads are
+// passed through unchanged.
+func NewDryRunClient(c client.Client) client.Client {
+	return &dryRunClient{Client: client.NewDryRunClient(c)}
+}
+
+// dryRunClient logs the outcome of the writes made by a dry-run client.
+type dryRunClient struct {
+	client.Client
+}
+
+func (c *dryRunClient) Create(ctx context.Context, obj client.Object, opts ...client.CreateOption) error {
+	err := c.Client.Create(ctx, obj, opts...)
+	logDryRun(ctx, "create", obj, err, "object", client.ObjectKeyFromObject(obj))
+	return err
+}
+
+func (c *dryRunClient) Update(ctx context.Context, obj client.Object, opts ...client.UpdateOption) error {
+	err := c.Client.Update(ctx, obj, opts...)
+	logDryRun(ctx, "update", obj, err, "object", client.ObjectKeyFromObject(obj))
+	return err
+}
+
+func (c *dryRunClient) Patch(ctx context.Context, obj client.Object, patch client.Patch, opts ...client.PatchOption) error {
+	err := c.Client.Patch(ctx, obj, patch, opts...)
+	logDryRun(ctx, "patch", obj, err, "object", client.ObjectKeyFromObject(obj))
+	return err
+}
+
+func (c *dryRunClient) Delete(ctx context.Context, obj client.Object, opts ...client.DeleteOption) error {
+	err := c.Client.Delete(ctx, obj, opts...)
+	logDryRun(ctx, "delete", obj, err, "object", client.ObjectKeyFromObject(obj))
+	return err
+}
+
+func (c *dryRunClient) DeleteAllOf(ctx context.Context, obj client.Object, opts ...client.DeleteAllOfOption) error {
+	err := c.Client.DeleteAllOf(ctx, obj, opts...)
+	// obj is only a type template here, so log the targeted namespace instead of its key.
+	deleteOpts := (&client.DeleteAllOfOptions{}).ApplyOptions(opts)
+	logDryRun(ctx, "deletecollection", obj, err, "namespace", deleteOpts.Namespace)
+	return err
+}
+
+func (c *dryRunClient) Status() client.SubResourceWriter {
+	return c.SubResource("status")
+}
+
+func (c *dryRunClient) SubResource(subResource string) client.SubResourceClient {
+	return &dryRunSubResourceClient{
+		SubResourceClient: c.Client.SubResource(subResource),
+		subResource:       subResource,
+	}
+}
+
+// dryRunSubResourceClient logs the outcome of the subresource writes made by a
+// dry-run client.
+type dryRunSubResourceClient struct {
+	client.SubResourceClient
+	subResource string
+}
+
+func (c *dryRunSubResourceClient) Create(ctx context.Context, obj client.Object, subResource client.Object, opts ...client.SubResourceCreateOption) error {
+	err := c.SubResourceClient.Create(ctx, obj, subResource, opts...)
+	// subResource is usually a bare request body such as an Eviction, so log the
+	// key of the parent object it was created for.
+	logDryRun(ctx, "create/"+c.subResource, subResource, err, "object", client.ObjectKeyFromObject(obj))
+	return err
+}
+
+func (c *dryRunSubResourceClient) Update(ctx context.Context, obj client.Object, opts ...client.SubResourceUpdateOption) error {
+	err := c.SubResourceClient.Update(ctx, obj, opts...)
+	logDryRun(ctx, "update/"+c.subResource, obj, err, "object", client.ObjectKeyFromObject(obj))
+	return err
+}
+
+func (c *dryRunSubResourceClient) Patch(ctx context.Context, obj client.Object, patch client.Patch, opts ...client.SubResourcePatchOption) error {
+	err := c.SubResourceClient.Patch(ctx, obj, patch, opts...)
+	logDryRun(ctx, "patch/"+c.subResource, obj, err, "object", client.ObjectKeyFromObject(obj))
+	return err
+}
+
+// logDryRun logs the object the API server returned for a dry-run write, or
+// the error if the server rejected it. keysAndValues identify the write target.
+func logDryRun(ctx context.Context, verb string, obj client.Object, err error, keysAndValues ...interface{}) {
+	logger := log.FromContext(ctx).
+		WithValues("verb", verb, "type", fmt.Sprintf("%T", obj)).
+		WithValues(keysAndValues...)
+	if err != nil {
+		logger.Error(err, "dry-run write rejected")
+		return
+	}
+	logger.Info("dry-run write accepted, not persisted", "result", obj)
+}
+
+// NewDryRunRecorder returns an EventRecorder that logs Events instead of
+// writing them to the API server, for use alongside NewDryRunClient.
+func NewDryRunRecorder(logger logr.Logger) record.EventRecorder {
+	return &dryRunRecorder{logger: logger}
+}
+
+// dryRunRecorder logs Events instead of recording them.
+type dryRunRecorder struct {
+	logger logr.Logger
+}
+
+func (r *dryRunRecorder) Event(object runtime.Object, eventtype, reason, message string) {
+	logger := r.logger
+	if obj, ok := object.(client.Object); ok {
+		logger = logger.WithValues("object", client.ObjectKeyFromObject(obj))
+	}
+	logger.Info("dry-run event not recorded", "type", eventtype, "reason", reason, "message", message)
+}
+
+func (r *dryRunRecorder) Eventf(object runtime.Object, eventtype, reason, messageFmt string, args ...interface{}) {
+	r.Event(object, eventtype, reason, fmt.Sprintf(messageFmt, args...))
+}
+
+func (r *dryRunRecorder) AnnotatedEventf(object runtime.Object, _ map[string]string, eventtype, reason, messageFmt string, args ...interface{}) {
+	r.Eventf(object, eventtype, reason, messageFmt, args...)
+}
@@ -0,0 +1,127 @@
+/*
+Copyright 2023.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+package controller
+
+import (
+	"context"
+
+	"github.com/go-logr/logr/funcr"
+	. "github.com/onsi/ginkgo/v2"
+	. "github.com/onsi/gomega"
+
+	corev1 "k8s.io/api/core/v1"
+	apierrors "k8s.io/apimachinery/pkg/api/errors"
+	metav1 "k8s.io/apimachinery/pkg/apis/meta/v1"
+	"k8s.io/apimachinery/pkg/runtime"
+	"sigs.k8s.io/controller-runtime/pkg/client"
+	"sigs.k8s.io/controller-runtime/pkg/client/fake"
+	"sigs.k8s.io/controller-runtime/pkg/log"
+)
+
+var _ = Describe("Dry run", func() {
+	var (
+		ctx        context.Context
+		logLines   []string
+		baseClient client.Client
+		existing   *corev1.Pod
+	)
+
+	BeforeEach(func() {
+		logLines = nil
+		logger := funcr.New(func(prefix, args string) {
+			logLines = append(logLines, args)
+		}, funcr.Options{})
+		ctx = log.IntoContext(context.Background(), logger)
+
+		testScheme := runtime.NewScheme()
+		Expect(corev1.AddToScheme(testScheme)).To(Succeed())
+
+		existing = &corev1.Pod{
+			ObjectMeta: metav1.ObjectMeta{Name: "existing", Namespace: "default"},
+			Status:     corev1.PodStatus{Phase: corev1.PodPending},
+		}
+		baseClient = fake.NewClientBuilder().
+			WithScheme(testScheme).
+			WithObjects(existing).
+			WithStatusSubresource(&corev1.Pod{}).
+			Build()
+	})
+
+	Describe("NewDryRunClient", func() {
+		var dryRunClient client.Client
+
+		BeforeEach(func() {
+			dryRunClient = NewDryRunClient(baseClient)
+		})
+
+		It("does not persist created objects", func() {
+			created := &corev1.Pod{ObjectMeta: metav1.ObjectMeta{Name: "created", Namespace: "default"}}
+			Expect(dryRunClient.Create(ctx, created)).To(Succeed())
+
+			err := baseClient.Get(ctx, client.ObjectKeyFromObject(created), &corev1.Pod{})
+			Expect(apierrors.IsNotFound(err)).To(BeTrue())
+			Expect(logLines).To(ContainElement(And(
+				ContainSubstring("dry-run write accepted"),
+				ContainSubstring(`"verb"="create"`),
+				ContainSubstring("default/created"),
+			)))
+		})
+
+		It("does not persist patches", func() {
+			patched := existing.DeepCopy()
+			patch := client.MergeFrom(patched.DeepCopy())
+			patched.Labels = map[string]string{"dry-run": "true"}
+			Expect(dryRunClient.Patch(ctx, patched, patch)).To(Succeed())
+
+			current := &corev1.Pod{}
+			Expect(baseClient.Get(ctx, client.ObjectKeyFromObject(existing), current)).To(Succeed())
+			Expect(current.Labels).NotTo(HaveKey("dry-run"))
+			Expect(logLines).To(ContainElement(ContainSubstring(`"verb"="patch"`)))
+		})
+
+		It("does not persist status updates", func() {
+			current := &corev1.Pod{}
+			Expect(baseClient.Get(ctx, client.ObjectKeyFromObject(existing), current)).To(Succeed())
+			current.Status.Phase = corev1.PodRunning
+			Expect(dryRunClient.Status().Update(ctx, current)).To(Succeed())
+
+			Expect(baseClient.Get(ctx, client.ObjectKeyFromObject(existing), current)).To(Succeed())
+			Expect(current.Status.Phase).To(Equal(corev1.PodPending))
+			Expect(logLines).To(ContainElement(And(
+				ContainSubstring(`"verb"="update/status"`),
+				ContainSubstring("default/existing"),
+			)))
+		})
+	})
+
+	Describe("NewDryRunRecorder", func() {
+		It("logs Events without recording them", func() {
+			recorder := NewDryRunRecorder(log.FromContext(ctx))
+			recorder.Eventf(existing, corev1.EventTypeNormal, "DryRunReason", "message %d", 1)
+			recorder.AnnotatedEventf(existing, map[string]string{"a": "b"}, corev1.EventTypeWarning, "DryRunReason", "message")
+
+			events := &corev1.EventList{}
+			Expect(baseClient.List(ctx, events)).To(Succeed())
+			Expect(events.Items).To(BeEmpty())
+			Expect(logLines).To(HaveLen(2))
+			Expect(logLines).To(HaveEach(And(
+				ContainSubstring("dry-run event not recorded"),
+				ContainSubstring(`"reason"="DryRunReason"`),
+			)))
+		})
+	})
+})
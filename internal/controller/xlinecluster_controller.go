@@ -18,8 +18,13 @@ package controller
 
 import (
 	"context"
+	"sync"
+	"time"
 
+	corev1 "k8s.io/api/core/v1"
+	apierrors "k8s.io/apimachinery/pkg/api/errors"
 	"k8s.io/apimachinery/pkg/runtime"
+	"k8s.io/client-go/tools/record"
 	ctrl "sigs.k8s.io/controller-runtime"
 	"sigs.k8s.io/controller-runtime/pkg/client"
 	"sigs.k8s.io/controller-runtime/pkg/log"
@@ -27,15 +32,34 @@ import (
 	xlinekvstoredatenlordcomv1alpha1 "github.com/xline-kv/xline-operator/api/v1alpha1"
 )
 
+// DefaultRequeueInterval is the default period after which a successfully
+// reconciled XlineCluster is reconciled again.
+const DefaultRequeueInterval = 600 * time.Second
+
+// PausedAnnotation suspends reconciliation of an XlineCluster when set to "true",
+// leaving all of its subresources untouched until it is removed.
+const PausedAnnotation = "xlineoperator.datenlord.io/paused"
+
 // XlineClusterReconciler reconciles a XlineCluster object
 type XlineClusterReconciler struct {
 	client.Client
 	Scheme *runtime.Scheme
+	// RequeueInterval is the period after which a successfully reconciled
+	// XlineCluster is reconciled again. Zero disables periodic requeue.
+	RequeueInterval time.Duration
+	// Recorder records Events against reconciled XlineClusters. Optional; no
+	// Events are emitted when nil.
+	Recorder record.EventRecorder
+
+	// paused holds the types.NamespacedName of every cluster currently seen
+	// paused, so ReconcilePaused is recorded once per pause.
+	paused sync.Map
 }
 
 //+kubebuilder:rbac:groups=xline.kvstore.datenlord.com,resources=xlineclusters,verbs=get;list;watch;create;update;patch;delete
 //+kubebuilder:rbac:groups=xline.kvstore.datenlord.com,resources=xlineclusters/status,verbs=get;update;patch
 //+kubebuilder:rbac:groups=xline.kvstore.datenlord.com,resources=xlineclusters/finalizers,verbs=update
+//+kubebuilder:rbac:groups="",resources=events,verbs=create;patch
 
 // Reconcile is part of the main kubernetes reconciliation loop which aims to
 // move the current state of the cluster closer to the desired state.
@@ -47,11 +71,30 @@ type XlineClusterReconciler struct {
 // For more details, check Reconcile and its Result here:
 // - https://pkg.go.dev/sigs.k8s.io/controller-runtime@v0.16.3/pkg/reconcile
 func (r *XlineClusterReconciler) Reconcile(ctx context.Context, req ctrl.Request) (ctrl.Result, error) {
-	_ = log.FromContext(ctx)
+	logger := log.FromContext(ctx)
+
+	cluster := &xlinekvstoredatenlordcomv1alpha1.XlineCluster{}
+	if err := r.Get(ctx, req.NamespacedName, cluster); err != nil {
+		if apierrors.IsNotFound(err) {
+			r.paused.Delete(req.NamespacedName)
+		}
+		// A deleted cluster needs no further reconciliation, so only requeue on real errors.
+		return ctrl.Result{}, client.IgnoreNotFound(err)
+	}
+
+	if cluster.Annotations[PausedAnnotation] == "true" {
+		logger.Info("reconciliation paused, skipping", "annotation", PausedAnnotation)
+		if _, seen := r.paused.LoadOrStore(req.NamespacedName, struct{}{}); !seen && r.Recorder != nil {
+			r.Recorder.Eventf(cluster, corev1.EventTypeNormal, "ReconcilePaused",
+				"Reconciliation is paused by the %s annotation", PausedAnnotation)
+		}
+		return ctrl.Result{RequeueAfter: r.RequeueInterval}, nil
+	}
+	r.paused.Delete(req.NamespacedName)
 
 	// TODO(user): your logic here
 
-	return ctrl.Result{}, nil
+	return ctrl.Result{RequeueAfter: r.RequeueInterval}, nil
 }
 
 // SetupWithManager sets up the controller with the Manager.
@@ -0,0 +1,159 @@
+/*
+Copyright 2023.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+package controller
+
+import (
+	"context"
+	"errors"
+	"time"
+
+	. "github.com/onsi/ginkgo/v2"
+	. "github.com/onsi/gomega"
+
+	metav1 "k8s.io/apimachinery/pkg/apis/meta/v1"
+	"k8s.io/apimachinery/pkg/runtime"
+	"k8s.io/client-go/tools/record"
+	ctrl "sigs.k8s.io/controller-runtime"
+	"sigs.k8s.io/controller-runtime/pkg/client"
+	"sigs.k8s.io/controller-runtime/pkg/client/fake"
+	"sigs.k8s.io/controller-runtime/pkg/client/interceptor"
+
+	xlinekvstoredatenlordcomv1alpha1 "github.com/xline-kv/xline-operator/api/v1alpha1"
+)
+
+var _ = Describe("XlineCluster controller", func() {
+	Context("when the cluster carries the paused annotation", func() {
+		var (
+			ctx        context.Context
+			cluster    *xlinekvstoredatenlordcomv1alpha1.XlineCluster
+			baseClient client.Client
+			writes     []string
+			recorder   *record.FakeRecorder
+			reconciler *XlineClusterReconciler
+			req        ctrl.Request
+		)
+
+		BeforeEach(func() {
+			ctx = context.Background()
+			testScheme := runtime.NewScheme()
+			Expect(xlinekvstoredatenlordcomv1alpha1.AddToScheme(testScheme)).To(Succeed())
+
+			cluster = &xlinekvstoredatenlordcomv1alpha1.XlineCluster{
+				ObjectMeta: metav1.ObjectMeta{
+					Name:        "paused-cluster",
+					Namespace:   "default",
+					Annotations: map[string]string{PausedAnnotation: "true"},
+				},
+			}
+
+			// The reconciler goes through an intercepted client that rejects every
+			// write, while the test edits the cluster through the underlying client.
+			writes = nil
+			errWrite := errors.New("unexpected write to the API server")
+			fakeClient := fake.NewClientBuilder().
+				WithScheme(testScheme).
+				WithObjects(cluster).
+				Build()
+			baseClient = fakeClient
+			recorder = record.NewFakeRecorder(10)
+
+			reconciler = &XlineClusterReconciler{
+				Client: interceptor.NewClient(fakeClient, interceptor.Funcs{
+					Create: func(_ context.Context, _ client.WithWatch, _ client.Object, _ ...client.CreateOption) error {
+						writes = append(writes, "create")
+						return errWrite
+					},
+					Update: func(_ context.Context, _ client.WithWatch, _ client.Object, _ ...client.UpdateOption) error {
+						writes = append(writes, "update")
+						return errWrite
+					},
+					Patch: func(_ context.Context, _ client.WithWatch, _ client.Object, _ client.Patch, _ ...client.PatchOption) error {
+						writes = append(writes, "patch")
+						return errWrite
+					},
+					Delete: func(_ context.Context, _ client.WithWatch, _ client.Object, _ ...client.DeleteOption) error {
+						writes = append(writes, "delete")
+						return errWrite
+					},
+					SubResourceCreate: func(_ context.Context, _ client.Client, subResourceName string,
+						_ client.Object, _ client.Object, _ ...client.SubResourceCreateOption) error {
+						writes = append(writes, "create/"+subResourceName)
+						return errWrite
+					},
+					SubResourceUpdate: func(_ context.Context, _ client.Client, subResourceName string,
+						_ client.Object, _ ...client.SubResourceUpdateOption) error {
+						writes = append(writes, "update/"+subResourceName)
+						return errWrite
+					},
+					SubResourcePatch: func(_ context.Context, _ client.Client, subResourceName string,
+						_ client.Object, _ client.Patch, _ ...client.SubResourcePatchOption) error {
+						writes = append(writes, "patch/"+subResourceName)
+						return errWrite
+					},
+				}),
+				Scheme:          testScheme,
+				RequeueInterval: time.Minute,
+				Recorder:        recorder,
+			}
+			req = ctrl.Request{NamespacedName: client.ObjectKeyFromObject(cluster)}
+		})
+
+		reconcile := func() {
+			result, err := reconciler.Reconcile(ctx, req)
+			Expect(err).NotTo(HaveOccurred())
+			Expect(result.RequeueAfter).To(Equal(reconciler.RequeueInterval))
+		}
+
+		setPaused := func(paused bool) {
+			current := &xlinekvstoredatenlordcomv1alpha1.XlineCluster{}
+			Expect(baseClient.Get(ctx, req.NamespacedName, current)).To(Succeed())
+			if paused {
+				current.Annotations = map[string]string{PausedAnnotation: "true"}
+			} else {
+				delete(current.Annotations, PausedAnnotation)
+			}
+			Expect(baseClient.Update(ctx, current)).To(Succeed())
+		}
+
+		It("skips reconciliation without writing to the API server", func() {
+			By("reconciling the paused cluster twice")
+			reconcile()
+			reconcile()
+
+			Expect(writes).To(BeEmpty())
+			Expect(recorder.Events).To(Receive(ContainSubstring("ReconcilePaused")))
+			Expect(recorder.Events).NotTo(Receive(), "ReconcilePaused should be recorded once per pause")
+		})
+
+		It("records ReconcilePaused again after the cluster is unpaused and paused again", func() {
+			reconcile()
+			Expect(recorder.Events).To(Receive(ContainSubstring("ReconcilePaused")))
+
+			By("removing the paused annotation")
+			setPaused(false)
+			reconcile()
+			Expect(recorder.Events).NotTo(Receive())
+
+			By("adding the paused annotation back")
+			setPaused(true)
+			reconcile()
+			Expect(recorder.Events).To(Receive(ContainSubstring("ReconcilePaused")))
+
+			Expect(writes).To(BeEmpty())
+		})
+	})
+})